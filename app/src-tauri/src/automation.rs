@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::process::Command;
+
+/// The target apps tracked by `OnboardingData.permissions.automation`.
+const KNOWN_APPS: &[&str] = &["Mail", "Calendar", "Reminders", "Notes", "Safari"];
+
+/// macOS reports this AppleEvent error when Automation/AppleEvents access
+/// has been explicitly denied for the target application.
+const ERR_AE_EVENT_NOT_PERMITTED: &str = "-1743";
+
+/// Tri-state result of an Automation (AppleEvents) permission check; the
+/// Automation counterpart of `check_accessibility_permission`, which reports
+/// the Accessibility TCC category as a plain bool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomationStatus {
+    Granted,
+    Denied,
+    NotDetermined,
+}
+
+/// Run a minimal, side-effect-free AppleScript `tell` against `app` and map
+/// the result to an [`AutomationStatus`].
+///
+/// `osascript` returns a non-zero exit status both when the event is denied
+/// (error -1743) and for other scripting failures (e.g. the app isn't
+/// installed), so the stderr text is inspected to tell those apart; anything
+/// that isn't a clean grant or a clear denial is reported as not-yet-determined
+/// so the onboarding UI can still prompt the user.
+///
+/// `app` is spliced directly into the AppleScript source, so callers MUST
+/// restrict it to [`KNOWN_APPS`] first -- this is not safe to call with
+/// arbitrary frontend-supplied strings.
+fn probe_automation_permission(app: &str) -> AutomationStatus {
+    let script = format!("tell application \"{}\" to get name", app);
+    let output = Command::new("osascript").arg("-e").arg(&script).output();
+
+    match output {
+        Ok(output) if output.status.success() => AutomationStatus::Granted,
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains(ERR_AE_EVENT_NOT_PERMITTED) {
+                AutomationStatus::Denied
+            } else {
+                AutomationStatus::NotDetermined
+            }
+        }
+        Err(_) => AutomationStatus::NotDetermined,
+    }
+}
+
+// `app` may come straight from the webview via the `check_automation_permission`
+// command, and `probe_automation_permission` splices it unescaped into an
+// AppleScript string -- this allowlist check runs on every platform (not
+// just under `#[cfg(target_os = "macos")]`) so it can't be bypassed or left
+// untested on non-macOS targets.
+pub fn check_automation_permission(app: &str) -> AutomationStatus {
+    if !KNOWN_APPS.contains(&app) {
+        return AutomationStatus::NotDetermined;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        probe_automation_permission(app)
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        AutomationStatus::Granted
+    }
+}
+
+pub fn check_all_automation_permissions() -> HashMap<String, AutomationStatus> {
+    KNOWN_APPS
+        .iter()
+        .map(|app| (app.to_string(), check_automation_permission(app)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_app_names_outside_the_known_allowlist() {
+        // A string crafted to break out of the `tell application "..."`
+        // block must never reach `probe_automation_permission`/`osascript`.
+        let injected = "Mail\" to get pid of application \"Finder";
+        assert_eq!(check_automation_permission(injected), AutomationStatus::NotDetermined);
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+/// Well-known bin directories that are missing from the PATH Tauri apps
+/// inherit when launched from Finder/Dock (`/usr/bin:/bin:/usr/sbin:/sbin`).
+const FALLBACK_BIN_DIRS: &[&str] = &[
+    "/opt/homebrew/bin",
+    "/opt/homebrew/sbin",
+    "/usr/local/bin",
+    "~/.cargo/bin",
+    "~/.local/bin",
+    "~/.pyenv/shims",
+    "~/.nvm/current/bin",
+];
+
+fn expand_home(path: &str) -> PathBuf {
+    if let Some(rest) = path.strip_prefix("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(rest);
+        }
+    }
+    PathBuf::from(path)
+}
+
+/// Split a `PATH`-style string into its component directories, dropping
+/// empty entries produced by leading/trailing/doubled `:` separators.
+fn split_path_entries(path: &str) -> Vec<PathBuf> {
+    path.split(':')
+        .filter(|entry| !entry.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// De-duplicate a list of paths while preserving first-seen order.
+fn dedup_preserve_order(entries: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if seen.insert(entry.clone()) {
+            out.push(entry);
+        }
+    }
+    out
+}
+
+/// On macOS, spawn a login+interactive shell to capture the PATH the user
+/// actually sees in a terminal (populated by their shell rc files), since
+/// GUI-launched apps never source `.zshrc`/`.zprofile`.
+#[cfg(target_os = "macos")]
+fn shell_login_path() -> Option<String> {
+    let output = Command::new("/bin/zsh")
+        .args(["-ilc", "printf %s \"$PATH\""])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let trimmed = stdout.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn shell_login_path() -> Option<String> {
+    None
+}
+
+/// Build a normalized `PATH` for spawning dev-tool detection probes.
+///
+/// Starts from the process's inherited `PATH`, merges in the user's real
+/// login-shell `PATH` on macOS (best effort, bounded by a short timeout so a
+/// hung shell rc never blocks onboarding), then appends well-known bin
+/// directories, and finally de-duplicates while preserving first-seen order.
+pub fn build_normalized_path() -> String {
+    let inherited = std::env::var("PATH").unwrap_or_default();
+    let mut entries = split_path_entries(&inherited);
+
+    if let Some(login_path) = run_with_timeout(shell_login_path, Duration::from_secs(2)) {
+        entries.extend(split_path_entries(&login_path));
+    }
+
+    entries.extend(FALLBACK_BIN_DIRS.iter().map(|dir| expand_home(dir)));
+
+    let entries = dedup_preserve_order(entries);
+    std::env::join_paths(entries)
+        .map(|joined| joined.to_string_lossy().into_owned())
+        .unwrap_or(inherited)
+}
+
+/// Run `f` on a background thread, giving up and returning `None` if it
+/// doesn't finish within `timeout`.
+fn run_with_timeout<T, F>(f: F, timeout: Duration) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Option<T> + Send + 'static,
+{
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = tx.send(f());
+    });
+    rx.recv_timeout(timeout).ok().flatten()
+}
+
+/// Construct a `Command` with a normalized `PATH` set, suitable for spawning
+/// dev-tool detection probes (`brew --version`, `python3 --version`, ...).
+///
+/// Never sets an environment variable to an empty value; if the normalized
+/// `PATH` somehow comes back empty, the inherited environment is left as-is.
+pub fn probe_command(program: &str) -> Command {
+    let mut cmd = Command::new(program);
+    let normalized = build_normalized_path();
+    if !normalized.is_empty() {
+        cmd.env("PATH", normalized);
+    }
+    cmd
+}
@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tauri::RunEvent;
 
+mod automation;
+mod env;
+mod logging;
+mod migration;
+mod reveal;
+
 // Onboarding state structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OnboardingState {
@@ -81,7 +87,7 @@ impl Default for OnboardingState {
         automation.insert("Safari".to_string(), false);
 
         Self {
-            version: 1,
+            version: migration::CURRENT_VERSION,
             completed: false,
             current_step: "welcome".to_string(),
             data: OnboardingData {
@@ -126,48 +132,84 @@ fn get_pid_path() -> Result<PathBuf, String> {
 fn stop_service_if_running() {
     if let Ok(pid_path) = get_pid_path() {
         if pid_path.exists() {
-            if let Ok(pid_str) = std::fs::read_to_string(&pid_path) {
-                if let Ok(pid) = pid_str.trim().parse::<i32>() {
-                    // Try to kill the process
-                    let _ = std::process::Command::new("kill")
-                        .arg(pid.to_string())
-                        .output();
-                    // Remove the PID file
-                    let _ = std::fs::remove_file(&pid_path);
-                }
+            match std::fs::read_to_string(&pid_path) {
+                Ok(pid_str) => match pid_str.trim().parse::<i32>() {
+                    Ok(pid) => {
+                        match std::process::Command::new("kill").arg(pid.to_string()).output() {
+                            Ok(output) if output.status.success() => {
+                                log::info!("Stopped service with pid {}", pid)
+                            }
+                            Ok(output) => log::warn!(
+                                "kill {} exited with {}: {}",
+                                pid,
+                                output.status,
+                                String::from_utf8_lossy(&output.stderr)
+                            ),
+                            Err(e) => log::error!("Failed to run kill {}: {}", pid, e),
+                        }
+                        if let Err(e) = std::fs::remove_file(&pid_path) {
+                            log::warn!("Failed to remove pid file {}: {}", pid_path.display(), e);
+                        }
+                    }
+                    Err(e) => log::warn!(
+                        "Unparseable pid file {}: {} ({:?})",
+                        pid_path.display(),
+                        e,
+                        pid_str
+                    ),
+                },
+                Err(e) => log::warn!("Failed to read pid file {}: {}", pid_path.display(), e),
             }
         }
     }
 }
 
-// Read onboarding state from disk
+// Read onboarding state from disk, migrating it to the current schema version first
 #[tauri::command]
 fn read_onboarding_state() -> Result<OnboardingState, String> {
     let path = get_onboarding_path()?;
-    if path.exists() {
-        let content = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
-        serde_json::from_str(&content).map_err(|e| e.to_string())
-    } else {
-        Ok(OnboardingState::default())
+    log::info!("Reading onboarding state from {}", path.display());
+    if !path.exists() {
+        return Ok(OnboardingState::default());
     }
+
+    let content = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let (state, migrated) = migration::migrate(&content).map_err(|e| {
+        log::error!("Failed to migrate {}: {}", path.display(), e);
+        e.to_string()
+    })?;
+
+    if migrated {
+        log::info!(
+            "Migrated {} to schema version {}",
+            path.display(),
+            migration::CURRENT_VERSION
+        );
+        if let Err(e) = migration::write_atomic(&path, &state) {
+            log::warn!("Failed to persist migrated onboarding state: {}", e);
+        }
+    }
+
+    Ok(state)
 }
 
 // Write onboarding state to disk
 #[tauri::command]
 fn write_onboarding_state(state: OnboardingState) -> Result<(), String> {
     let path = get_onboarding_path()?;
+    log::info!("Writing onboarding state to {}", path.display());
     // Ensure directory exists
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
     }
-    let content = serde_json::to_string_pretty(&state).map_err(|e| e.to_string())?;
-    std::fs::write(&path, content).map_err(|e| e.to_string())
+    migration::write_atomic(&path, &state).map_err(|e| e.to_string())
 }
 
 // Read .env config file
 #[tauri::command]
 fn read_config() -> Result<String, String> {
     let path = get_env_path()?;
+    log::info!("Reading config from {}", path.display());
     if path.exists() {
         std::fs::read_to_string(&path).map_err(|e| e.to_string())
     } else {
@@ -179,6 +221,7 @@ fn read_config() -> Result<String, String> {
 #[tauri::command]
 fn write_config(content: String) -> Result<(), String> {
     let path = get_env_path()?;
+    log::info!("Writing config to {}", path.display());
     // Ensure directory exists
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
@@ -186,6 +229,12 @@ fn write_config(content: String) -> Result<(), String> {
     std::fs::write(&path, content).map_err(|e| e.to_string())
 }
 
+// Read the last `lines` lines of the log file, for onboarding troubleshooting
+#[tauri::command]
+fn read_recent_logs(lines: usize) -> Result<Vec<String>, String> {
+    logging::read_recent_logs(lines)
+}
+
 // Open System Preferences to a specific pane
 #[tauri::command]
 fn open_system_preferences(pane: String) -> Result<(), String> {
@@ -196,6 +245,55 @@ fn open_system_preferences(pane: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Run `program --version` using the normalized dev-tool PATH and return the
+/// trimmed stdout on success, or `None` if the tool isn't installed.
+fn probe_version(program: &str) -> Option<String> {
+    let output = env::probe_command(program).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if version.is_empty() {
+        None
+    } else {
+        Some(version)
+    }
+}
+
+fn probe_installed(program: &str) -> bool {
+    env::probe_command(program)
+        .arg("--version")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+// Detect homebrew/python/node/npx using a PATH normalized for GUI-launched apps
+#[tauri::command]
+fn detect_dev_tools() -> DevToolsData {
+    let homebrew = match probe_version("brew") {
+        Some(version) => DevToolInfo { installed: true, version },
+        None => DevToolInfo { installed: false, version: String::new() },
+    };
+    let python = match probe_version("python3") {
+        Some(version) => DevToolInfo { installed: true, version },
+        None => DevToolInfo { installed: false, version: String::new() },
+    };
+    let node = match probe_version("node") {
+        Some(version) => DevToolInfo { installed: true, version },
+        None => DevToolInfo { installed: false, version: String::new() },
+    };
+    let npx = NpxInfo { installed: probe_installed("npx") };
+
+    DevToolsData {
+        homebrew,
+        python,
+        node,
+        npx,
+        skipped: false,
+    }
+}
+
 // Check if accessibility permission is granted for THIS app
 #[tauri::command]
 fn check_accessibility_permission() -> bool {
@@ -209,12 +307,52 @@ fn check_accessibility_permission() -> bool {
     }
 }
 
+// Check whether this app has Automation/AppleEvents access to `app`
+#[tauri::command]
+fn check_automation_permission(app: String) -> automation::AutomationStatus {
+    automation::check_automation_permission(&app)
+}
+
+// Check Automation/AppleEvents access for every app the onboarding UI tracks
+#[tauri::command]
+fn check_all_automation_permissions() -> std::collections::HashMap<String, automation::AutomationStatus> {
+    automation::check_all_automation_permissions()
+}
+
+// Reveal `path` in the platform's file manager
+#[tauri::command]
+fn reveal_in_folder(
+    path: String,
+    #[cfg(target_os = "linux")] dbus: tauri::State<reveal::DbusState>,
+) -> Result<(), String> {
+    #[cfg(target_os = "linux")]
+    {
+        reveal::reveal_in_folder(&PathBuf::from(path), &dbus)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        reveal::reveal_in_folder(&PathBuf::from(path))
+    }
+}
+
+// Open `path` with the platform's default handler
+#[tauri::command]
+fn open_path(path: String) -> Result<(), String> {
+    reveal::open_path(&PathBuf::from(path))
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let builder = tauri::Builder::default()
+        .plugin(logging::plugin())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
-        .plugin(tauri_plugin_fs::init())
+        .plugin(tauri_plugin_fs::init());
+
+    #[cfg(target_os = "linux")]
+    let builder = builder.manage(reveal::DbusState::default());
+
+    builder
         .invoke_handler(tauri::generate_handler![
             read_onboarding_state,
             write_onboarding_state,
@@ -222,12 +360,18 @@ pub fn run() {
             write_config,
             open_system_preferences,
             check_accessibility_permission,
+            check_automation_permission,
+            check_all_automation_permissions,
+            detect_dev_tools,
+            reveal_in_folder,
+            open_path,
+            read_recent_logs,
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(|_app_handle, event| {
             if let RunEvent::Exit = event {
-                // Stop the service when the app exits
+                log::info!("App exiting, stopping service if running");
                 stop_service_if_running();
             }
         });
@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+use tauri_plugin_log::{Target, TargetKind};
+
+/// Directory logs are written to: `~/.macbot/logs/`.
+fn logs_dir() -> Result<PathBuf, String> {
+    dirs::home_dir()
+        .map(|p| p.join(".macbot").join("logs"))
+        .ok_or_else(|| "Could not find home directory".to_string())
+}
+
+/// Build the `tauri-plugin-log` plugin, writing to a rotating file under
+/// `~/.macbot/logs/` in addition to stdout so `cargo tauri dev` still shows
+/// breadcrumbs while iterating.
+pub fn plugin() -> tauri::plugin::TauriPlugin<tauri::Wry> {
+    tauri_plugin_log::Builder::new()
+        .level(log::LevelFilter::Info)
+        .targets([
+            Target::new(TargetKind::Stdout),
+            Target::new(TargetKind::Folder {
+                path: logs_dir().unwrap_or_else(|_| PathBuf::from(".")),
+                file_name: Some("macbot".to_string()),
+            }),
+        ])
+        .build()
+}
+
+/// Read the last `lines` lines of the most recently modified log file, for the onboarding UI's
+/// troubleshooting tail.
+pub fn read_recent_logs(lines: usize) -> Result<Vec<String>, String> {
+    let dir = logs_dir()?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    // tauri-plugin-log names files `<file_name>.log` (plus date-rotated
+    // variants); the current log is always the most recently modified one.
+    let newest = std::fs::read_dir(&dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "log"))
+        .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok());
+
+    let Some(entry) = newest else {
+        return Ok(Vec::new());
+    };
+
+    let content = std::fs::read_to_string(entry.path()).map_err(|e| e.to_string())?;
+    let all_lines: Vec<&str> = content.lines().collect();
+    let start = all_lines.len().saturating_sub(lines);
+    Ok(all_lines[start..].iter().map(|line| line.to_string()).collect())
+}
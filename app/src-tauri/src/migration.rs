@@ -0,0 +1,161 @@
+use serde_json::Value;
+use std::io::Write;
+use std::path::Path;
+
+use crate::OnboardingState;
+
+/// The schema version this build of the app understands. Bump this whenever
+/// `OnboardingState`/`OnboardingData` gains or changes a field in a way that
+/// needs an explicit migration rather than `#[serde(default)]`.
+pub const CURRENT_VERSION: u32 = 1;
+
+#[derive(Debug)]
+pub enum MigrationError {
+    /// The stored file is a version newer than this build supports, e.g.
+    /// after a downgrade. Returned distinctly so the UI can warn the user
+    /// instead of silently resetting their onboarding progress.
+    FutureVersion { found: u32, supported: u32 },
+    Io(std::io::Error),
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrationError::FutureVersion { found, supported } => write!(
+                f,
+                "onboarding.json is schema version {} but this app only supports up to {}; please update the app",
+                found, supported
+            ),
+            MigrationError::Io(e) => write!(f, "{}", e),
+            MigrationError::Json(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<std::io::Error> for MigrationError {
+    fn from(e: std::io::Error) -> Self {
+        MigrationError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for MigrationError {
+    fn from(e: serde_json::Error) -> Self {
+        MigrationError::Json(e)
+    }
+}
+
+impl From<MigrationError> for String {
+    fn from(e: MigrationError) -> Self {
+        e.to_string()
+    }
+}
+
+/// Ordered chain of migrations starting at the v1 baseline: entry `n` brings
+/// the JSON from version `n + 1` up to `n + 2`. Add a new entry here (and bump `CURRENT_VERSION`) every
+/// time the schema changes in a way old files can't already tolerate via
+/// `#[serde(default)]`.
+const MIGRATIONS: &[fn(&mut Value)] = &[
+    // v1 is the baseline schema; no migrations exist yet.
+];
+
+fn read_version(value: &Value) -> u32 {
+    value.get("version").and_then(Value::as_u64).unwrap_or(0) as u32
+}
+
+/// Parse `content` as loosely-typed JSON, run any migrations needed to bring
+/// it up to [`CURRENT_VERSION`], and deserialize the result into a concrete
+/// [`OnboardingState`]. Returns, alongside the state, whether a migration
+/// happened, so the caller can decide whether to persist the upgrade.
+pub fn migrate(content: &str) -> Result<(OnboardingState, bool), MigrationError> {
+    let mut value: Value = serde_json::from_str(content)?;
+    let found_version = read_version(&value);
+
+    if found_version > CURRENT_VERSION {
+        return Err(MigrationError::FutureVersion {
+            found: found_version,
+            supported: CURRENT_VERSION,
+        });
+    }
+
+    let migrated = found_version < CURRENT_VERSION;
+    // A file with no `version` field is treated as the v1 baseline.
+    let mut version = found_version.max(1) as usize;
+    while version - 1 < MIGRATIONS.len() {
+        MIGRATIONS[version - 1](&mut value);
+        version += 1;
+    }
+    // Always stamp `version` when below `CURRENT_VERSION`, even if no
+    // `MIGRATIONS` entries ran (e.g. an empty chain, or a file that's
+    // missing `version` entirely) -- otherwise `OnboardingState::version`
+    // is left absent and deserialization fails with "missing field `version`".
+    if migrated {
+        value["version"] = Value::from(CURRENT_VERSION);
+    }
+
+    let state: OnboardingState = serde_json::from_value(value)?;
+    Ok((state, migrated))
+}
+
+/// Write `state` to `path` atomically (temp file + rename) so a crash
+/// mid-write can't leave onboarding.json truncated or corrupted.
+pub fn write_atomic(path: &Path, state: &OnboardingState) -> Result<(), MigrationError> {
+    let content = serde_json::to_string_pretty(state)?;
+    let tmp_path = path.with_extension("json.tmp");
+    {
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+    }
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_value(version: Option<u32>) -> Value {
+        let mut value = serde_json::json!({
+            "completed": false,
+            "current_step": "welcome",
+            "data": {
+                "permissions": { "accessibility": false, "automation": {} },
+                "api_key": { "provider": "openai", "configured": false, "verified": false },
+                "telegram": { "configured": false, "skipped": false }
+            }
+        });
+        if let Some(version) = version {
+            value["version"] = Value::from(version);
+        }
+        value
+    }
+
+    #[test]
+    fn current_version_is_not_reported_as_migrated() {
+        let json = sample_value(Some(CURRENT_VERSION)).to_string();
+        let (state, migrated) = migrate(&json).unwrap();
+        assert!(!migrated);
+        assert_eq!(state.version, CURRENT_VERSION);
+    }
+
+    #[test]
+    fn missing_version_field_defaults_to_zero_and_migrates() {
+        let json = sample_value(None).to_string();
+        let (state, _migrated) = migrate(&json).unwrap();
+        assert_eq!(state.version, CURRENT_VERSION);
+    }
+
+    #[test]
+    fn future_version_is_rejected_instead_of_reset_to_defaults() {
+        let json = sample_value(Some(CURRENT_VERSION + 1)).to_string();
+        let err = migrate(&json).unwrap_err();
+        match err {
+            MigrationError::FutureVersion { found, supported } => {
+                assert_eq!(found, CURRENT_VERSION + 1);
+                assert_eq!(supported, CURRENT_VERSION);
+            }
+            other => panic!("expected FutureVersion, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Directory `open_path`/`reveal_in_folder` are allowed to touch, matching
+/// the `fs` capability scope (`$HOME/.macbot/**`).
+fn permitted_root() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".macbot"))
+}
+
+/// Collapse `.`/`..` components without touching the filesystem (unlike
+/// `Path::canonicalize`, this works even if `path` doesn't exist yet).
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                out.pop();
+            }
+            std::path::Component::CurDir => {}
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Returns an error unless `path` lives under the permitted root, so this
+/// subsystem composes with the fs capability scope rather than widening it.
+///
+/// `path` is normalized first -- a plain lexical `starts_with` check is not
+/// enough, since `$HOME/.macbot/../../etc/passwd` shares a path prefix with
+/// the permitted root while actually resolving outside it.
+fn ensure_permitted(path: &Path) -> Result<(), String> {
+    let root = permitted_root().ok_or_else(|| "Could not find home directory".to_string())?;
+    if normalize(path).starts_with(normalize(&root)) {
+        Ok(())
+    } else {
+        Err(format!("Path is outside the permitted directory: {}", root.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_traversal_out_of_the_permitted_root() {
+        let root = permitted_root().unwrap();
+        let escaped = root.join("../../etc/passwd");
+        assert!(ensure_permitted(&escaped).is_err());
+    }
+
+    #[test]
+    fn allows_paths_under_the_permitted_root() {
+        let root = permitted_root().unwrap();
+        assert!(ensure_permitted(&root.join("onboarding.json")).is_ok());
+        assert!(ensure_permitted(&root.join("logs/macbot.log")).is_ok());
+    }
+
+    #[test]
+    fn normalize_collapses_dot_and_dotdot_components() {
+        let input = Path::new("/a/b/../c/./d");
+        assert_eq!(normalize(input), PathBuf::from("/a/c/d"));
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use linux::DbusState;
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::path::Path;
+    use std::sync::Mutex;
+    use zbus::blocking::Connection;
+    use zbus::zvariant::Array;
+
+    /// Holds a lazily-established session D-Bus connection so repeated
+    /// reveal requests don't each pay the connection setup cost.
+    #[derive(Default)]
+    pub struct DbusState(Mutex<Option<Connection>>);
+
+    impl DbusState {
+        fn with_connection<T>(&self, f: impl FnOnce(&Connection) -> T) -> Option<T> {
+            let mut guard = self.0.lock().ok()?;
+            if guard.is_none() {
+                *guard = Connection::session().ok();
+            }
+            guard.as_ref().map(f)
+        }
+
+        /// Ask the file manager to highlight `path`, falling back to `None`
+        /// if the bus is unavailable or the call otherwise fails (e.g. a
+        /// comma in the path breaking the call on some file managers).
+        pub fn show_item(&self, path: &Path) -> Option<()> {
+            let uri = format!("file://{}", path.display());
+            self.with_connection(|conn| {
+                let uris = Array::from(vec![uri.as_str()]);
+                conn.call_method(
+                    Some("org.freedesktop.FileManager1"),
+                    "/org/freedesktop/FileManager1",
+                    Some("org.freedesktop.FileManager1"),
+                    "ShowItems",
+                    &(uris, ""),
+                )
+                .ok()
+            })
+            .flatten()
+            .map(|_| ())
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn open_dir(dir: &Path) -> Result<(), String> {
+    Command::new("xdg-open")
+        .arg(dir)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Reveal `path` in the platform's file manager, with the containing file
+/// highlighted when the platform supports it.
+#[cfg(target_os = "macos")]
+pub fn reveal_in_folder(path: &Path) -> Result<(), String> {
+    ensure_permitted(path)?;
+    Command::new("open").arg("-R").arg(path).spawn().map(|_| ()).map_err(|e| e.to_string())
+}
+
+#[cfg(target_os = "linux")]
+pub fn reveal_in_folder(path: &Path, dbus: &linux::DbusState) -> Result<(), String> {
+    ensure_permitted(path)?;
+    if dbus.show_item(path).is_some() {
+        Ok(())
+    } else {
+        // Bus unavailable, or the call failed (e.g. a comma in the path
+        // breaking the D-Bus call) -- fall back to opening the parent dir.
+        open_dir(path.parent().unwrap_or(path))
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+pub fn reveal_in_folder(path: &Path) -> Result<(), String> {
+    ensure_permitted(path)?;
+    Err("Revealing files is not supported on this platform".to_string())
+}
+
+/// Open `path` with the platform's default handler for it.
+pub fn open_path(path: &Path) -> Result<(), String> {
+    ensure_permitted(path)?;
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("open").arg(path).spawn().map(|_| ()).map_err(|e| e.to_string())
+    }
+    #[cfg(target_os = "linux")]
+    {
+        open_dir(path)
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        Err("Opening files is not supported on this platform".to_string())
+    }
+}